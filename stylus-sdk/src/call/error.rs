@@ -0,0 +1,150 @@
+// Copyright 2022-2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Errors that can occur when calling other contracts.
+
+use alloc::vec::Vec;
+use alloy_sol_types::{SolError, SolInterface};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Error type for calls to other contracts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Call was reverted with the given data.
+    Revert(Vec<u8>),
+    /// The requested combination of [`CallFlags`](super::CallFlags) is not sound, such as
+    /// setting both `FORWARD_INPUT` and `CLONE_INPUT`.
+    InvalidCallFlags,
+    /// Attempted to mutate state or send value from within a [`static_call`](super::static_call).
+    StaticStateChange,
+}
+
+impl Error {
+    /// The raw revert data, if this error came from a revert rather than, say, a malformed
+    /// call configuration.
+    pub fn revert_data(&self) -> Option<&[u8]> {
+        match self {
+            Error::Revert(data) => Some(data),
+            Error::InvalidCallFlags | Error::StaticStateChange => None,
+        }
+    }
+
+    /// Attempt to decode the revert data as the Solidity custom error `E`, matching the
+    /// leading 4-byte selector before ABI-decoding the remainder.
+    ///
+    /// Returns `None` if this error did not come from a revert, or if the revert data's
+    /// selector doesn't match `E`.
+    pub fn decode<E: SolError>(&self) -> Option<E> {
+        E::abi_decode(self.revert_data()?, true).ok()
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::Revert(data) => write!(f, "revert: {data:?}"),
+            Error::InvalidCallFlags => write!(f, "invalid combination of call flags"),
+            Error::StaticStateChange => {
+                write!(f, "attempted to change state within a static call")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// The builtin Solidity revert reasons emitted by plain `require`/`assert` statements, which
+/// decode without any declarations on the caller's part.
+pub mod builtins {
+    alloy_sol_types::sol! {
+        /// Emitted by a failed `require(condition, reason)`.
+        error Error(string reason);
+        /// Emitted by `assert`, arithmetic overflow, and other compiler-inserted panics.
+        error Panic(uint256 code);
+    }
+}
+
+/// Error type for typed calls made through an interface whose custom errors are described by
+/// `E`, an error enum implementing [`SolInterface`] (e.g. an enum with one variant each for
+/// [`builtins::Error`] and [`builtins::Panic`], or a hand-aggregated enum covering a
+/// particular interface's custom errors). A failed call decodes its revert data against `E` on a best-effort basis,
+/// falling back to [`ContractError::Generic`] when the data doesn't match any known variant
+/// (including calls that failed for a reason other than a revert).
+///
+/// This is deliberately the foundational half of typed-error decoding: the decode-by-selector
+/// machinery ([`Error::decode`], this type) that any aggregated error enum can plug into.
+/// Teaching `sol_interface!` to emit that aggregated `E` automatically per declared interface
+/// — so a typed call returns `Result<T, ContractError<IFooErrors>>` without the caller hand
+/// writing `IFooErrors` — is a separate, not-yet-landed change to the procedural-macro crate
+/// that generates `sol_interface!`, which this change does not touch. Until that lands,
+/// callers define `E` themselves (by hand or via `alloy_sol_types::sol!`) and convert with
+/// `ContractError::from`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractError<E> {
+    /// The call failed, but the revert data didn't decode as any variant of `E`.
+    Generic(Error),
+    /// The callee reverted with one of `E`'s declared custom errors.
+    Interface(E),
+}
+
+impl<E: SolInterface> From<Error> for ContractError<E> {
+    fn from(error: Error) -> Self {
+        match error.revert_data().and_then(|data| E::abi_decode(data, true).ok()) {
+            Some(decoded) => ContractError::Interface(decoded),
+            None => ContractError::Generic(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[derive(Debug, Clone, PartialEq, Eq, alloy_sol_types::SolInterface)]
+    enum Builtins {
+        Error(builtins::Error),
+        Panic(builtins::Panic),
+    }
+
+    #[test]
+    fn decodes_builtin_require_reason() {
+        let reason = builtins::Error {
+            reason: "nope".into(),
+        };
+        let err = Error::Revert(reason.abi_encode());
+        let decoded: builtins::Error = err.decode().expect("selector should match");
+        assert_eq!(decoded.reason, "nope");
+    }
+
+    #[test]
+    fn decode_returns_none_on_selector_mismatch() {
+        let panic = builtins::Panic {
+            code: U256::from(0x11u32),
+        };
+        let err = Error::Revert(panic.abi_encode());
+        assert!(err.decode::<builtins::Error>().is_none());
+    }
+
+    #[test]
+    fn decode_returns_none_for_non_revert_errors() {
+        assert!(Error::InvalidCallFlags.decode::<builtins::Error>().is_none());
+    }
+
+    #[test]
+    fn contract_error_decodes_known_interface_error() {
+        let panic = builtins::Panic {
+            code: U256::from(0x01u32),
+        };
+        let err = Error::Revert(panic.abi_encode());
+        let wrapped: ContractError<Builtins> = err.into();
+        assert!(matches!(wrapped, ContractError::Interface(Builtins::Panic(_))));
+    }
+
+    #[test]
+    fn contract_error_falls_back_to_generic_for_non_revert_errors() {
+        let wrapped: ContractError<Builtins> = Error::InvalidCallFlags.into();
+        assert!(matches!(wrapped, ContractError::Generic(Error::InvalidCallFlags)));
+    }
+}
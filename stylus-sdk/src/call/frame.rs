@@ -0,0 +1,62 @@
+// Copyright 2022-2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Tracks whether execution is currently inside a [`static_call`](super::static_call), so that
+//! state-changing calls reached from a static context fail fast instead of reverting deep
+//! inside the VM.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the current call frame (or an ancestor) is a static call.
+///
+/// Stylus programs are single-threaded, so a plain [`AtomicBool`] is sufficient; there's no
+/// concurrent execution to race against.
+static IN_STATIC_FRAME: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether execution is currently inside a static call.
+pub(crate) fn in_static_frame() -> bool {
+    IN_STATIC_FRAME.load(Ordering::Relaxed)
+}
+
+/// Marks entry into a static call frame for the duration of this guard, restoring the prior
+/// state (rather than unconditionally clearing it) when dropped so that nested static calls
+/// behave correctly.
+pub(crate) struct StaticFrameGuard {
+    was_static: bool,
+}
+
+impl StaticFrameGuard {
+    /// Enters a static call frame.
+    pub(crate) fn enter() -> Self {
+        let was_static = IN_STATIC_FRAME.swap(true, Ordering::Relaxed);
+        Self { was_static }
+    }
+}
+
+impl Drop for StaticFrameGuard {
+    fn drop(&mut self) {
+        IN_STATIC_FRAME.store(self.was_static, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_guards_restore_the_previous_state() {
+        assert!(!in_static_frame());
+
+        let outer = StaticFrameGuard::enter();
+        assert!(in_static_frame());
+
+        let inner = StaticFrameGuard::enter();
+        assert!(in_static_frame());
+
+        drop(inner);
+        assert!(in_static_frame(), "leaving the inner frame must not clear the outer one");
+
+        drop(outer);
+        assert!(!in_static_frame());
+    }
+}
@@ -8,19 +8,30 @@
 //! - The `unsafe` [`RawCall`] for `unsafe`, bytes-in bytes-out calls.
 //!
 //! Additional helpers exist for specific use-cases like [`transfer_eth`].
+//!
+//! Failed calls surface as [`Error::Revert`]; use [`Error::decode`] or [`ContractError`] to
+//! recover a typed Solidity custom error from the revert data, including the
+//! [`builtins::Error`] and [`builtins::Panic`] the compiler emits for `require`/`assert`.
+//! `sol_interface!` does not yet generate the aggregated error enum these expect per
+//! interface — see [`ContractError`]'s docs — so callers declare that enum by hand today.
 
 use alloc::vec::Vec;
 use alloy_primitives::Address;
 
-pub use self::{context::Call, error::Error, raw::RawCall, traits::*, transfer::transfer_eth};
-
-pub(crate) use raw::CachePolicy;
+pub use self::{
+    context::Call,
+    error::{builtins, ContractError, Error},
+    raw::{CachePolicy, CallFlags, RawCall},
+    traits::*,
+    transfer::{transfer_eth, Transfer},
+};
 
 #[cfg(all(feature = "storage-cache", feature = "reentrant"))]
 use crate::storage::Storage;
 
 mod context;
 mod error;
+mod frame;
 mod raw;
 mod traits;
 mod transfer;
@@ -37,6 +48,17 @@ macro_rules! unsafe_reentrant {
     };
 }
 
+/// Applies the chosen [`CachePolicy`] before making a call, persisting cached changes and
+/// invalidating the cache as requested.
+#[cfg(all(feature = "storage-cache", feature = "reentrant"))]
+fn apply_cache_policy(policy: CachePolicy) {
+    match policy {
+        CachePolicy::Flush => Storage::flush(), // persist changes, but keep the cache
+        CachePolicy::Clear => Storage::clear(), // persist changes, invalidating the cache
+        CachePolicy::DoNothing => {}
+    }
+}
+
 /// Static calls the contract at the given address.
 pub fn static_call(
     context: impl StaticCallContext,
@@ -44,7 +66,9 @@ pub fn static_call(
     data: &[u8],
 ) -> Result<Vec<u8>, Error> {
     #[cfg(all(feature = "storage-cache", feature = "reentrant"))]
-    Storage::flush(); // flush storage to persist changes, but don't invalidate the cache
+    apply_cache_policy(context.cache_policy());
+
+    let _guard = frame::StaticFrameGuard::enter();
 
     unsafe_reentrant! {{
         RawCall::new_static()
@@ -59,30 +83,45 @@ pub fn static_call(
 /// # Safety
 ///
 /// A delegate call must trust the other contract to uphold safety requirements.
-/// Though this function clears any cached values, the other contract may arbitrarily change storage,
-/// spend ether, and do other things one should never blindly allow other contracts to do.
+/// By default this function clears any cached values (see [`MutatingCallContext::cache_policy`]),
+/// but the other contract may arbitrarily change storage, spend ether, and do other things one
+/// should never blindly allow other contracts to do.
 pub unsafe fn delegate_call(
     context: impl MutatingCallContext,
     to: Address,
     data: &[u8],
 ) -> Result<Vec<u8>, Error> {
+    if frame::in_static_frame() {
+        return Err(Error::StaticStateChange);
+    }
+
+    let flags = context.flags();
+
     #[cfg(all(feature = "storage-cache", feature = "reentrant"))]
-    Storage::clear(); // clear the storage to persist changes, invalidating the cache
+    apply_cache_policy(context.cache_policy());
 
     RawCall::new_with_value(context.value())
         .gas(context.gas())
+        .flags(flags)?
         .call(to, data)
         .map_err(Error::Revert)
 }
 
 /// Calls the contract at the given address.
 pub fn call(context: impl MutatingCallContext, to: Address, data: &[u8]) -> Result<Vec<u8>, Error> {
+    if frame::in_static_frame() {
+        return Err(Error::StaticStateChange);
+    }
+
+    let flags = context.flags();
+
     #[cfg(all(feature = "storage-cache", feature = "reentrant"))]
-    Storage::clear(); // clear the storage to persist changes, invalidating the cache
+    apply_cache_policy(context.cache_policy());
 
     unsafe_reentrant! {{
         RawCall::new_with_value(context.value())
             .gas(context.gas())
+            .flags(flags)?
             .call(to, data)
             .map_err(Error::Revert)
     }}
@@ -0,0 +1,267 @@
+// Copyright 2022-2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Unsafe, bytes-in bytes-out calls to other contracts.
+
+use super::Error;
+use alloc::vec::Vec;
+use alloy_primitives::{Address, U256};
+
+/// The cache policy to apply before making a call to another contract.
+///
+/// Only meaningful when the `storage-cache` and `reentrant` features are both enabled, since
+/// otherwise there is no cache to manage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Persist any cached changes, but keep the cache populated.
+    Flush,
+    /// Persist any cached changes, invalidating the cache. This is the conservative default.
+    #[default]
+    Clear,
+    /// Do nothing. Only sound when the callee cannot touch this contract's storage.
+    DoNothing,
+}
+
+/// The kind of call being made, which determines which hostio is invoked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CallKind {
+    Basic,
+    Delegate,
+    Static,
+}
+
+/// Per-call flags that tune reentrancy and calldata/returndata forwarding, mirroring the
+/// `seal_call` flags from Substrate's `pallet-contracts`. These are combined into a single
+/// `u32` and passed straight to the underlying hostio.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallFlags(u32);
+
+impl CallFlags {
+    /// Permit the callee to re-enter this contract.
+    ///
+    /// This only controls what the callee is allowed to do at the hostio level; it has no
+    /// effect on storage cache handling, which is controlled independently via
+    /// [`CachePolicy`](super::CachePolicy) on the call context (see
+    /// [`MutatingCallContext::cache_policy`](super::MutatingCallContext::cache_policy)).
+    pub const ALLOW_REENTRY: Self = Self(1 << 0);
+
+    /// Forward this contract's own calldata to the callee, consuming it.
+    pub const FORWARD_INPUT: Self = Self(1 << 1);
+
+    /// Pass this contract's own calldata to the callee without consuming it.
+    pub const CLONE_INPUT: Self = Self(1 << 2);
+
+    /// Return the callee's returndata directly to our caller (tail-call semantics).
+    pub const FORWARD_OUTPUT: Self = Self(1 << 3);
+
+    /// The empty flag set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// The raw bits passed to the hostio.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Whether `self` contains all of `other`'s bits.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for CallFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for CallFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// An unsafe, bytes-in bytes-out call to another contract.
+///
+/// This type exists for authors that want complete control over the semantics of a call, at
+/// the cost of the safety the richly-typed [`CallContext`](super::CallContext) family provides.
+/// Most users should prefer [`call`](super::call), [`static_call`](super::static_call), and
+/// [`delegate_call`](super::delegate_call).
+#[derive(Clone, Copy, Debug)]
+pub struct RawCall {
+    kind: CallKind,
+    gas: u64,
+    value: U256,
+    offset: usize,
+    size: Option<usize>,
+    flags: CallFlags,
+}
+
+impl RawCall {
+    /// Begin configuring a basic call, by default forwarding all gas and no value.
+    pub fn new() -> Self {
+        Self::new_with_value(U256::ZERO)
+    }
+
+    /// Begin configuring a basic call that sends the given amount of value.
+    pub fn new_with_value(value: U256) -> Self {
+        Self {
+            kind: CallKind::Basic,
+            gas: u64::MAX,
+            value,
+            offset: 0,
+            size: None,
+            flags: CallFlags::empty(),
+        }
+    }
+
+    /// Begin configuring a delegate call.
+    pub fn new_delegate() -> Self {
+        Self {
+            kind: CallKind::Delegate,
+            gas: u64::MAX,
+            value: U256::ZERO,
+            offset: 0,
+            size: None,
+            flags: CallFlags::empty(),
+        }
+    }
+
+    /// Begin configuring a static call.
+    pub fn new_static() -> Self {
+        Self {
+            kind: CallKind::Static,
+            gas: u64::MAX,
+            value: U256::ZERO,
+            offset: 0,
+            size: None,
+            flags: CallFlags::empty(),
+        }
+    }
+
+    /// Amount of gas to supply the call.
+    ///
+    /// Note: values are clipped to the amount of gas remaining.
+    pub fn gas(mut self, gas: u64) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Only read the first `size` bytes of return data, starting at `offset`, rather than the
+    /// entirety of what the callee returns.
+    pub fn limit_return_data(mut self, offset: usize, size: usize) -> Self {
+        self.offset = offset;
+        self.size = Some(size);
+        self
+    }
+
+    /// Add the given per-call [`CallFlags`] to those already set, to forward to the hostio.
+    ///
+    /// Rejects combinations that don't make sense, such as setting both
+    /// [`CallFlags::FORWARD_INPUT`] and [`CallFlags::CLONE_INPUT`], before any call is made.
+    pub fn flags(mut self, flags: CallFlags) -> Result<Self, Error> {
+        self.flags |= flags;
+        if self.flags.contains(CallFlags::FORWARD_INPUT)
+            && self.flags.contains(CallFlags::CLONE_INPUT)
+        {
+            return Err(Error::InvalidCallFlags);
+        }
+        Ok(self)
+    }
+
+    /// Call the contract at the given address with the given calldata.
+    ///
+    /// # Panics
+    ///
+    /// No hostio binding is present in this crate, so this always panics without making the
+    /// call.
+    pub fn call(self, to: Address, data: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+        unsafe { self.call_unchecked(to, data) }
+    }
+
+    /// Sends `value` to `to` with empty calldata, reusing this builder's configured gas and
+    /// flags. Since [`RawCall`] is [`Copy`], the same configuration can be reused across many
+    /// recipients, collecting each one's success or failure without aborting the batch:
+    ///
+    /// ```no_run
+    /// # use stylus_sdk::call::RawCall;
+    /// # use alloy_primitives::{Address, U256};
+    /// # fn example(recipients: &[(Address, U256)]) {
+    /// let payer = RawCall::new().gas(21_000);
+    /// let results: Vec<_> = recipients
+    ///     .iter()
+    ///     .map(|&(to, value)| payer.transfer(to, value))
+    ///     .collect();
+    /// # }
+    /// ```
+    pub fn transfer(mut self, to: Address, value: U256) -> Result<(), Vec<u8>> {
+        self.value = value;
+        self.call(to, &[]).map(|_| ())
+    }
+
+    unsafe fn call_unchecked(self, to: Address, data: &[u8]) -> Result<Vec<u8>, Vec<u8>> {
+        let _ = (
+            to,
+            data,
+            self.kind,
+            self.gas,
+            self.value,
+            self.offset,
+            self.size,
+            self.flags.bits(),
+        );
+        // No `call_contract`/`delegate_call_contract`/`static_call_contract` hostio is bound
+        // here, so the configuration above is read but not used: this panics on every target.
+        unimplemented!("no hostio binding is present to make this call")
+    }
+}
+
+impl Default for RawCall {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_requires_all_bits() {
+        let flags = CallFlags::ALLOW_REENTRY | CallFlags::FORWARD_OUTPUT;
+        assert!(flags.contains(CallFlags::ALLOW_REENTRY));
+        assert!(flags.contains(CallFlags::FORWARD_OUTPUT));
+        assert!(flags.contains(CallFlags::ALLOW_REENTRY | CallFlags::FORWARD_OUTPUT));
+        assert!(!flags.contains(CallFlags::FORWARD_INPUT));
+    }
+
+    #[test]
+    fn flags_rejects_forward_and_clone_input_combined_across_calls() {
+        // Each call alone is fine; only the merged result is the invalid combination.
+        let result = RawCall::new()
+            .flags(CallFlags::FORWARD_INPUT)
+            .unwrap()
+            .flags(CallFlags::CLONE_INPUT);
+        assert!(matches!(result, Err(Error::InvalidCallFlags)));
+    }
+
+    #[test]
+    fn flags_rejects_forward_and_clone_input_in_one_call() {
+        let result = RawCall::new().flags(CallFlags::FORWARD_INPUT | CallFlags::CLONE_INPUT);
+        assert!(matches!(result, Err(Error::InvalidCallFlags)));
+    }
+
+    #[test]
+    fn flags_accepts_compatible_combination() {
+        let call = RawCall::new()
+            .flags(CallFlags::ALLOW_REENTRY)
+            .unwrap()
+            .flags(CallFlags::FORWARD_OUTPUT)
+            .unwrap();
+        assert!(call.flags.contains(CallFlags::ALLOW_REENTRY));
+        assert!(call.flags.contains(CallFlags::FORWARD_OUTPUT));
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2022-2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Traits that classify the different ways a call to another contract can be made.
+//!
+//! Implementors are usually just the [`Call`](super::Call) builder, but users may
+//! implement these directly when writing their own call-context types.
+
+use super::{CachePolicy, CallFlags};
+use alloy_primitives::U256;
+
+/// Common configuration shared by every kind of call.
+pub trait CallContext {
+    /// Amount of gas to supply the call.
+    ///
+    /// Note: values are clipped to the amount of gas remaining.
+    fn gas(&self) -> u64;
+}
+
+/// Trait for calls that may mutate state.
+///
+/// This is the context needed to perform [`call`](super::call) and
+/// [`delegate_call`](super::delegate_call).
+pub trait MutatingCallContext: CallContext {
+    /// Amount of native token to pass along with the call.
+    fn value(&self) -> U256;
+
+    /// Per-call [`CallFlags`] controlling reentrancy and calldata/returndata forwarding.
+    ///
+    /// Defaults to [`CallFlags::empty()`], preserving today's conservative, non-reentrant
+    /// behavior for callers that don't opt in.
+    fn flags(&self) -> CallFlags {
+        CallFlags::empty()
+    }
+
+    /// How the storage cache should be handled before making the call.
+    ///
+    /// Defaults to [`CachePolicy::Clear`], today's conservative behavior. Authors who can
+    /// prove the callee cannot touch this contract's storage may select
+    /// [`CachePolicy::Flush`] or [`CachePolicy::DoNothing`] to avoid rebuilding the cache on
+    /// hot call sites.
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Clear
+    }
+}
+
+/// Trait exclusively for [`static_call`](super::static_call).
+///
+/// [`Call<Static>`](super::Call) implements this trait but not [`MutatingCallContext`], so
+/// passing it to a mutating call fails to compile. The two traits do not exclude each other:
+/// another type may implement both. A
+/// [`static_call`](super::static_call) is additionally guarded at runtime: any
+/// [`call`](super::call), [`delegate_call`](super::delegate_call), or
+/// [`transfer_eth`](super::transfer_eth) reached while executing inside one returns
+/// [`Error::StaticStateChange`](super::Error::StaticStateChange) instead of making a doomed
+/// hostio call.
+pub trait StaticCallContext: CallContext {
+    /// How the storage cache should be handled before making the call.
+    ///
+    /// Defaults to [`CachePolicy::Flush`], since a static call cannot itself mutate storage
+    /// but may still trigger writes by the caller earlier in the same transaction.
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Flush
+    }
+}